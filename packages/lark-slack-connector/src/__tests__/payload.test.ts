@@ -0,0 +1,33 @@
+import { describe, it, expect } from 'vitest';
+import { buildWebhookPayload } from '../lark/payload';
+
+describe('buildWebhookPayload', () => {
+  it('should build an interactive card when a title is given', () => {
+    expect(buildWebhookPayload('[#general] Sample User: hello', '#general - Sample User')).toEqual({
+      msg_type: 'interactive',
+      card: {
+        header: { title: { tag: 'plain_text', content: '#general - Sample User' } },
+        elements: [{ tag: 'markdown', content: '[#general] Sample User: hello' }],
+      },
+    });
+  });
+
+  it('should set the header color on cards', () => {
+    const payload = buildWebhookPayload('hello', '#general - Sample User', 'blue');
+    expect(payload.msg_type === 'interactive' && payload.card.header.template).toBe('blue');
+  });
+
+  it('should ignore the color for text messages', () => {
+    expect(buildWebhookPayload('hello', undefined, 'blue')).toEqual({
+      msg_type: 'text',
+      content: { text: 'hello' },
+    });
+  });
+
+  it('should build a text message without a title', () => {
+    expect(buildWebhookPayload('hello')).toEqual({
+      msg_type: 'text',
+      content: { text: 'hello' },
+    });
+  });
+});
@@ -12,15 +12,28 @@ import {
 } from './types';
 import { validateConfig } from './config';
 
+const DEFAULT_THREAD_REPLY_PREFIX = '↳ ';
+const DEFAULT_TRUNCATION_MARKER = '…(省略)';
+
 export interface BridgeOptions {
   config: BridgeConfig;
 }
 
+export type PauseBehavior = 'drop' | 'queue';
+
 export class LarkSlackBridge extends EventEmitter {
   private config: BridgeConfig;
   private slackClients: Map<string, SlackClient> = new Map();
   private larkClient: LarkClient;
   private isRunning = false;
+  private paused = false;
+  private pauseBehavior: PauseBehavior = 'drop';
+  private heldMessages: Array<{ message: SlackMessage; workspaceId: string }> = [];
+  // Slack→Lark sends that failed and are waiting for another attempt
+  private retryQueue: Array<{ message: SlackMessage; workspaceId: string; attempts: number }> = [];
+  private retryTimer?: NodeJS.Timeout;
+  // Lark chat id → Slack thread ts, for larkToSlackThreading 'threadByConversation'
+  private larkThreads: Map<string, string>;
   private startedAt?: Date;
   private stats = {
     slackToLark: 0,
@@ -38,6 +51,7 @@ export class LarkSlackBridge extends EventEmitter {
     }
 
     this.config = options.config;
+    this.larkThreads = new Map(Object.entries(options.config.options?.larkThreadMap ?? {}));
 
     // Initialize Lark client
     this.larkClient = new LarkClient({
@@ -52,6 +66,10 @@ export class LarkSlackBridge extends EventEmitter {
         socketMode: options.config.slack.socketMode,
         logLevel: options.config.options?.logLevel,
         senderConfig: options.config.sender,
+        reconnect: {
+          maxRetries: options.config.options?.reconnectMaxRetries ?? 10,
+          baseDelayMs: options.config.options?.reconnectBaseDelayMs ?? 1000,
+        },
       });
 
       // Set up message forwarding
@@ -68,19 +86,42 @@ export class LarkSlackBridge extends EventEmitter {
     });
   }
 
-  private async handleSlackMessage(message: SlackMessage, workspaceId: string): Promise<void> {
+  /**
+   * Resolves to whether the message reached Lark. Injected test messages
+   * get one attempt: never held while paused or queued for a retry.
+   */
+  private async handleSlackMessage(message: SlackMessage, workspaceId: string, injected = false): Promise<boolean> {
     this.emitEvent('slack:message', { message, workspaceId });
 
     // Apply filters
     if (!this.shouldForwardSlackMessage(message)) {
-      return;
+      return false;
     }
 
+    if (this.paused) {
+      if (this.pauseBehavior === 'queue' && !injected) {
+        this.heldMessages.push({ message, workspaceId });
+        this.log('debug', `Paused: holding message from ${message.channel}`);
+      } else {
+        this.log('debug', `Paused: dropping message from ${message.channel}`);
+      }
+      return false;
+    }
+
+    return this.forwardSlackMessage(message, workspaceId, 0, !injected);
+  }
+
+  private async forwardSlackMessage(
+    message: SlackMessage,
+    workspaceId: string,
+    attempts = 0,
+    retry = true,
+  ): Promise<boolean> {
     // Find channel mapping or use default behavior
     const mapping = this.findChannelMapping(message.channel, 'slack-to-lark');
 
     try {
-      const formattedMessage = this.formatSlackToLark(message);
+      const formattedMessage = this.truncate(this.appendFooter(this.formatSlackToLark(message), message));
 
       if (mapping?.larkChat && this.larkClient.hasAppClient()) {
         // Send to specific Lark chat
@@ -90,7 +131,8 @@ export class LarkSlackBridge extends EventEmitter {
         const title = message.channelName
           ? `#${message.channelName} - ${message.userName || message.user}`
           : undefined;
-        await this.larkClient.sendWebhook(formattedMessage, title);
+        const color = this.config.options?.channelCardColors?.[message.channel];
+        await this.larkClient.sendWebhook(formattedMessage, title, color);
       }
 
       this.stats.slackToLark++;
@@ -99,6 +141,7 @@ export class LarkSlackBridge extends EventEmitter {
         message,
         workspaceId,
       });
+      return true;
     } catch (error) {
       this.stats.errors++;
       this.emitEvent('bridge:error', {
@@ -107,12 +150,36 @@ export class LarkSlackBridge extends EventEmitter {
         error,
       });
       this.log('error', `Failed to forward Slack message: ${error}`);
+      if (retry && attempts < (this.config.options?.maxRetries ?? 3)) {
+        this.retryQueue.push({ message, workspaceId, attempts: attempts + 1 });
+        this.scheduleRetry();
+      }
+      return false;
     }
   }
 
+  private scheduleRetry(): void {
+    if (this.retryTimer || this.retryQueue.length === 0) {
+      return;
+    }
+    this.retryTimer = setTimeout(async () => {
+      const batch = this.retryQueue.splice(0);
+      for (const { message, workspaceId, attempts } of batch) {
+        await this.forwardSlackMessage(message, workspaceId, attempts);
+      }
+      this.retryTimer = undefined;
+      this.scheduleRetry();
+    }, this.config.options?.retryDelayMs ?? 1000);
+  }
+
   private async handleLarkMessage(message: LarkMessage): Promise<void> {
     this.emitEvent('lark:message', { message });
 
+    if (this.config.options?.mode === 'oneWaySlackToLark') {
+      this.log('debug', `One-way mode: ignoring Lark message from chat ${message.chatId}`);
+      return;
+    }
+
     // Find channel mapping or use default channel
     const mapping = this.findChannelMapping(message.chatId, 'lark-to-slack');
     const defaultChannel = this.config.options?.defaultSlackChannel;
@@ -129,16 +196,26 @@ export class LarkSlackBridge extends EventEmitter {
       const slackClient = this.slackClients.values().next().value as SlackClient | undefined;
 
       if (slackClient) {
+        const threaded = this.config.options?.larkToSlackThreading === 'threadByConversation';
+        const threadTs = threaded ? this.larkThreads.get(message.chatId) : undefined;
+
         // Send as user if configured (松井大樹アカウントで送信)
         const sendAsUser = this.config.sender?.sendAsUser ?? false;
+        let postedTs: string | undefined;
         if (sendAsUser && slackClient.hasUserToken()) {
-          await slackClient.sendMessageAsUser(targetChannel, formattedMessage);
+          postedTs = await slackClient.sendMessageAsUser(targetChannel, formattedMessage, threadTs);
           this.log('info', `Forwarded Lark message to Slack as user: ${targetChannel}`);
         } else {
-          await slackClient.sendMessage(targetChannel, formattedMessage);
+          postedTs = await slackClient.sendMessage(targetChannel, formattedMessage, threadTs);
           this.log('info', `Forwarded Lark message to Slack: ${targetChannel}`);
         }
 
+        // The first message of a conversation becomes its thread parent
+        if (threaded && !threadTs && postedTs) {
+          this.larkThreads.set(message.chatId, postedTs);
+          this.emitEvent('bridge:thread', { larkConversationId: message.chatId, slackThreadTs: postedTs });
+        }
+
         this.stats.larkToSlack++;
         this.emitEvent('bridge:forward', {
           direction: 'lark-to-slack',
@@ -279,6 +356,23 @@ export class LarkSlackBridge extends EventEmitter {
       includeTimestamp: true,
       timezone: 'Asia/Tokyo',
     };
+    const isThreadReply = Boolean(message.threadTs && message.threadTs !== message.ts);
+    const threadPrefix = isThreadReply && this.config.options?.preserveThreads
+      ? (this.config.options.threadReplyPrefix || DEFAULT_THREAD_REPLY_PREFIX).trimEnd()
+      : undefined;
+
+    const template = this.config.options?.larkMessageTemplate;
+    if (template) {
+      const values: Record<string, string> = {
+        channel: message.channelName ? `#${message.channelName}` : message.channel,
+        author: message.userName || message.user,
+        text: this.convertMentions(message.text),
+        timestamp: this.formatTimestamp(message.ts, options.timezone),
+      };
+      const rendered = template.replace(/\{(channel|author|text|timestamp)\}/g, (_, name: string) => values[name]);
+      return threadPrefix ? `${threadPrefix} ${rendered}` : rendered;
+    }
+
     const parts: string[] = [];
 
     if (options.includeChannelName !== false && message.channelName) {
@@ -291,19 +385,65 @@ export class LarkSlackBridge extends EventEmitter {
       parts.push(`<@${message.user}>:`);
     }
 
-    parts.push(message.text);
+    parts.push(this.convertMentions(message.text));
+
+    if (threadPrefix) {
+      parts.unshift(threadPrefix);
+    }
 
     if (options.includeTimestamp !== false) {
-      const date = new Date(parseFloat(message.ts) * 1000);
-      const formatted = date.toLocaleString('ja-JP', {
-        timeZone: options.timezone ?? 'Asia/Tokyo',
-      });
-      parts.push(`\n📅 ${formatted}`);
+      parts.push(`\n📅 ${this.formatTimestamp(message.ts, options.timezone)}`);
     }
 
     return parts.join(' ');
   }
 
+  /**
+   * Rewrite `<@U123>` / `<@U123|name>` as Lark `<at user_id="ou_...">` tags.
+   * Unmapped users become plain `@name`/`@U123`.
+   */
+  private convertMentions(text: string): string {
+    // Runs without mappings too, so raw <@U123> never reaches Lark; the
+    // desktop's mention preview (convert_mentions) follows the same rules
+    const mappings = this.config.options?.userMappings ?? {};
+    return text.replace(/<@([^>|]+)(?:\|([^>]*))?>/g, (_, userId: string, name?: string) => {
+      const label = name || userId;
+      const openId = mappings[userId];
+      return openId ? `<at user_id="${openId}">${label}</at>` : `@${label}`;
+    });
+  }
+
+  private appendFooter(text: string, message: SlackMessage): string {
+    const footer = this.config.options?.larkMessageFooter;
+    if (!footer) {
+      return text;
+    }
+    const channel = message.channelName ? `#${message.channelName}` : message.channel;
+    return `${text}\n${footer.replace(/\{channel\}/g, channel)}`;
+  }
+
+  /**
+   * Cut to maxMessageLength characters, marker included, so Lark doesn't
+   * reject the request
+   */
+  private truncate(text: string): string {
+    const max = this.config.options?.maxMessageLength;
+    const chars = Array.from(text);
+    if (!max || chars.length <= max) {
+      return text;
+    }
+    const marker = this.config.options?.truncationMarker ?? DEFAULT_TRUNCATION_MARKER;
+    const keep = Math.max(0, max - Array.from(marker).length);
+    return chars.slice(0, keep).join('') + marker;
+  }
+
+  private formatTimestamp(ts: string, timezone?: string): string {
+    const date = new Date(parseFloat(ts) * 1000);
+    return date.toLocaleString('ja-JP', {
+      timeZone: timezone ?? 'Asia/Tokyo',
+    });
+  }
+
   private formatLarkToSlack(message: LarkMessage): string {
     const parts: string[] = [];
 
@@ -383,11 +523,68 @@ export class LarkSlackBridge extends EventEmitter {
       }
     }
 
+    if (this.retryTimer) {
+      clearTimeout(this.retryTimer);
+      this.retryTimer = undefined;
+    }
+
     this.isRunning = false;
     this.emitEvent('bridge:disconnected', {});
     this.log('info', 'Bridge stopped');
   }
 
+  /**
+   * Stop forwarding Slack messages without disconnecting; messages arriving
+   * meanwhile are dropped or held for resume() per `behavior`
+   */
+  pause(behavior?: PauseBehavior): void {
+    this.paused = true;
+    this.pauseBehavior = behavior ?? this.config.options?.pauseBehavior ?? 'drop';
+    this.log('info', `Forwarding paused (${this.pauseBehavior})`);
+  }
+
+  /**
+   * Resume forwarding, first sending any held messages in arrival order.
+   * Returns how many held messages were sent.
+   */
+  async resume(): Promise<number> {
+    this.paused = false;
+    const held = this.heldMessages.splice(0);
+    for (const { message, workspaceId } of held) {
+      await this.forwardSlackMessage(message, workspaceId);
+    }
+    this.log('info', `Forwarding resumed (${held.length} held messages sent)`);
+    return held.length;
+  }
+
+  isPaused(): boolean {
+    return this.paused;
+  }
+
+  /**
+   * Drop every Slack→Lark send waiting for a retry. Messages held while
+   * paused are kept. Returns how many were dropped.
+   */
+  flush(): number {
+    const dropped = this.retryQueue.splice(0).length;
+    this.log('info', `Retry queue flushed (${dropped} messages dropped)`);
+    return dropped;
+  }
+
+  /**
+   * Channels whose messages pass the channel filters. With no
+   * includeChannels every channel the bot is in is monitored (`all`),
+   * minus `excluded`.
+   */
+  getMonitoredChannels(): { all: boolean; channels: string[]; excluded: string[] } {
+    const include = this.config.filters?.includeChannels ?? [];
+    return {
+      all: include.length === 0,
+      channels: [...include],
+      excluded: [...(this.config.filters?.excludeChannels ?? [])],
+    };
+  }
+
   /**
    * Get bridge status
    */
@@ -404,6 +601,7 @@ export class LarkSlackBridge extends EventEmitter {
       larkConnected: this.larkClient.hasWebhook() || this.larkClient.hasAppClient(),
       workspaces,
       messageStats: { ...this.stats },
+      paused: this.paused,
       startedAt: this.startedAt,
       uptime: this.startedAt ? Date.now() - this.startedAt.getTime() : undefined,
     };
@@ -423,6 +621,16 @@ export class LarkSlackBridge extends EventEmitter {
     await this.larkClient.handleEvent(event);
   }
 
+  /**
+   * Run a synthetic Slack message through filtering and forwarding, as if
+   * it had arrived over the Slack connection. Resolves once Lark has
+   * accepted it (true) or it was filtered, paused or failed (false); a
+   * failed injection is not retried after the caller has its answer.
+   */
+  async injectSlackMessage(message: SlackMessage, workspaceId = 'default'): Promise<boolean> {
+    return this.handleSlackMessage(message, workspaceId, true);
+  }
+
   /**
    * Get Slack client for a workspace
    */
@@ -8,11 +8,26 @@
  * Protocol:
  * - Input (stdin): JSON config object
  * - Output (stdout): JSON status updates prefixed with "STATUS:" or "LOG:"
+ * - `THREAD:{"larkConversationId","slackThreadTs"}` when a Lark conversation
+ *   gets its Slack thread, so the desktop app can persist the mapping
+ * - `--protocol-version` prints PROTOCOL_VERSION and exits, so the desktop
+ *   app can detect a CLI that speaks a different stdout protocol
+ * - `--check-deps` prints `DEPS:{"missing":[...]}` and exits, so the desktop
+ *   app can report unshipped node_modules before a full start
  */
 
 import { BridgeServer } from '../server';
 import { BridgeConfig, BridgeStatus } from '../types';
 
+// Bump whenever the stdout line format changes; must match the Tauri shell
+export const PROTOCOL_VERSION = '1';
+
+type LogLevel = 'debug' | 'info' | 'warn' | 'error';
+const LOG_LEVELS: LogLevel[] = ['debug', 'info', 'warn', 'error'];
+
+// LOG: lines below this level are not written; set from the config once read
+let minLogLevel: LogLevel = 'info';
+
 interface MuteTimeRange {
   enabled: boolean;
   startHour: number;
@@ -30,6 +45,7 @@ interface DesktopConfig {
   slackBotToken: string;
   slackAppToken: string;
   slackSigningSecret?: string;
+  eventMode?: 'socket' | 'http'; // Never both: Slack would deliver every event twice
   slackUserToken?: string; // For sending as user (松井大樹)
   larkWebhookUrl: string;
   larkAppId?: string;
@@ -45,6 +61,48 @@ interface DesktopConfig {
   excludeKeywords?: string[];
   excludeUserIds?: string[];
   notificationSettings?: NotificationSettings;
+  logLevel?: LogLevel;
+  mode?: 'oneWaySlackToLark' | 'bidirectional';
+  debugMode?: boolean; // Enables /debug/inject on the local server
+  pauseBehavior?: 'drop' | 'queue';
+  reconnectMaxRetries?: number;
+  reconnectBaseDelayMs?: number;
+  preserveThreads?: boolean;
+  threadReplyPrefix?: string;
+  larkToSlackThreading?: 'flat' | 'threadByConversation';
+  larkThreadMap?: Record<string, string>;
+  botUsernameOverride?: string;
+  botIconUrl?: string;
+  channelCardColors?: Record<string, string>;
+  larkMessageTemplate?: string;
+  larkMessageFooter?: string;
+  userMappings?: Record<string, string>;
+  maxMessageLength?: number;
+  truncationMarker?: string;
+}
+
+// Same keys as SECRET_BRIDGE_KEYS in the Tauri shell
+const SECRET_CONFIG_KEYS = [
+  'slackBotToken',
+  'slackAppToken',
+  'slackSigningSecret',
+  'slackUserToken',
+  'larkWebhookUrl',
+  'larkAppSecret',
+] as const;
+const REDACTED = '********';
+
+/**
+ * The config as received, with set secrets replaced, for GET /config
+ */
+function redactConfig(config: DesktopConfig): Record<string, unknown> {
+  const redacted: Record<string, unknown> = { ...config };
+  for (const key of SECRET_CONFIG_KEYS) {
+    if (redacted[key]) {
+      redacted[key] = REDACTED;
+    }
+  }
+  return redacted;
 }
 
 function sendStatus(status: BridgeStatus): void {
@@ -55,12 +113,17 @@ function sendStatus(status: BridgeStatus): void {
       slackConnected: status.slackConnected,
       larkConnected: status.larkConnected,
       messageStats: status.messageStats,
+      paused: status.paused ?? false,
     },
   };
   console.log(`STATUS:${JSON.stringify(output)}`);
 }
 
 function sendLog(level: string, message: string): void {
+  const rank = LOG_LEVELS.indexOf(level as LogLevel);
+  if (rank !== -1 && rank < LOG_LEVELS.indexOf(minLogLevel)) {
+    return;
+  }
   console.log(`LOG:${JSON.stringify({ level, message, timestamp: new Date().toISOString() })}`);
 }
 
@@ -68,8 +131,12 @@ function sendError(error: string): void {
   console.log(`ERROR:${JSON.stringify({ error, timestamp: new Date().toISOString() })}`);
 }
 
+function sendThread(larkConversationId: string, slackThreadTs: string): void {
+  console.log(`THREAD:${JSON.stringify({ larkConversationId, slackThreadTs })}`);
+}
+
 function sendReady(port: number): void {
-  console.log(`READY:${JSON.stringify({ port })}`);
+  console.log(`READY:${JSON.stringify({ port, protocolVersion: PROTOCOL_VERSION })}`);
 }
 
 async function readConfig(): Promise<DesktopConfig> {
@@ -130,7 +197,7 @@ function createBridgeConfig(desktop: DesktopConfig): BridgeConfig {
           userToken: desktop.slackUserToken,
         },
       ],
-      socketMode: true,
+      socketMode: desktop.eventMode !== 'http',
     },
     lark: {
       webhookUrl: desktop.larkWebhookUrl,
@@ -141,6 +208,8 @@ function createBridgeConfig(desktop: DesktopConfig): BridgeConfig {
     sender: {
       sendAsUser: desktop.sendAsUser ?? true, // Default to sending as user
       slackUserToken: desktop.slackUserToken,
+      botUsername: desktop.botUsernameOverride || undefined,
+      botIconUrl: desktop.botIconUrl || undefined,
     },
     // Channel filters (Slack → Lark)
     filters: {
@@ -162,12 +231,50 @@ function createBridgeConfig(desktop: DesktopConfig): BridgeConfig {
       defaultSlackChannel: desktop.defaultSlackChannel,
       maxRetries: 3,
       retryDelayMs: 1000,
-      logLevel: 'info',
+      logLevel: desktop.logLevel ?? 'info',
+      mode: desktop.mode,
+      preserveThreads: desktop.preserveThreads ?? true,
+      threadReplyPrefix: desktop.threadReplyPrefix,
+      pauseBehavior: desktop.pauseBehavior,
+      reconnectMaxRetries: desktop.reconnectMaxRetries,
+      reconnectBaseDelayMs: desktop.reconnectBaseDelayMs,
+      larkToSlackThreading: desktop.larkToSlackThreading,
+      larkThreadMap: desktop.larkThreadMap,
+      channelCardColors: desktop.channelCardColors,
+      larkMessageTemplate: desktop.larkMessageTemplate || undefined,
+      larkMessageFooter: desktop.larkMessageFooter || undefined,
+      userMappings: desktop.userMappings,
+      maxMessageLength: desktop.maxMessageLength || undefined,
+      truncationMarker: desktop.truncationMarker,
     },
   };
 }
 
+// Runtime dependencies the bridge loads at start
+const RUNTIME_DEPENDENCIES = ['@larksuiteoapi/node-sdk', '@slack/bolt', '@slack/web-api', 'zod'];
+
+async function checkDependencies(): Promise<void> {
+  const missing: string[] = [];
+  for (const name of RUNTIME_DEPENDENCIES) {
+    try {
+      await import(name);
+    } catch {
+      missing.push(name);
+    }
+  }
+  console.log(`DEPS:${JSON.stringify({ missing })}`);
+}
+
 async function main(): Promise<void> {
+  if (process.argv.includes('--protocol-version')) {
+    console.log(PROTOCOL_VERSION);
+    return;
+  }
+  if (process.argv.includes('--check-deps')) {
+    await checkDependencies();
+    return;
+  }
+
   sendLog('info', 'デスクトップブリッジサーバー起動中...');
 
   let config: DesktopConfig;
@@ -177,12 +284,23 @@ async function main(): Promise<void> {
     sendError(`設定読み込みエラー: ${error}`);
     process.exit(1);
   }
+  if (config.logLevel && LOG_LEVELS.includes(config.logLevel)) {
+    minLogLevel = config.logLevel;
+  }
 
-  // Validate config
-  if (!config.slackBotToken || !config.slackAppToken) {
+  // Validate config: Socket Mode needs the app token, HTTP events the signing secret
+  if (!config.slackBotToken) {
     sendError('Slackトークンが設定されていません');
     process.exit(1);
   }
+  if (config.eventMode === 'http' ? !config.slackSigningSecret : !config.slackAppToken) {
+    sendError(
+      config.eventMode === 'http'
+        ? 'HTTPイベントモードにはSigning Secretが必要です'
+        : 'Socket ModeにはApp Tokenが必要です'
+    );
+    process.exit(1);
+  }
   if (!config.larkWebhookUrl) {
     sendError('Lark Webhook URLが設定されていません');
     process.exit(1);
@@ -192,10 +310,12 @@ async function main(): Promise<void> {
   const port = config.serverPort || 3456;
 
   try {
-    const server = new BridgeServer(bridgeConfig, { port }, {
+    const serverOptions = { port, debug: config.debugMode ?? false, reportedConfig: redactConfig(config) };
+    const server = new BridgeServer(bridgeConfig, serverOptions, {
       onStatusChange: sendStatus,
       onLog: sendLog,
       onError: (err) => sendError(err.message),
+      onThreadStarted: sendThread,
     });
 
     // Handle shutdown signals
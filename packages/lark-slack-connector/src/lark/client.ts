@@ -1,5 +1,6 @@
 import * as lark from '@larksuiteoapi/node-sdk';
 import { LarkConfig, LarkMessage } from '../types';
+import { buildWebhookPayload } from './payload';
 
 export interface LarkClientOptions {
   config: LarkConfig;
@@ -39,35 +40,12 @@ export class LarkClient {
   /**
    * Send message via webhook
    */
-  async sendWebhook(text: string, title?: string): Promise<boolean> {
+  async sendWebhook(text: string, title?: string, color?: string): Promise<boolean> {
     if (!this.config.webhookUrl) {
       throw new Error('Lark webhook URL is not configured');
     }
 
-    const payload = title
-      ? {
-          msg_type: 'interactive',
-          card: {
-            header: {
-              title: {
-                tag: 'plain_text',
-                content: title,
-              },
-            },
-            elements: [
-              {
-                tag: 'markdown',
-                content: text,
-              },
-            ],
-          },
-        }
-      : {
-          msg_type: 'text',
-          content: {
-            text,
-          },
-        };
+    const payload = buildWebhookPayload(text, title, color);
 
     const response = await fetch(this.config.webhookUrl, {
       method: 'POST',
@@ -1,2 +1,4 @@
 export { LarkClient } from './client';
 export type { LarkClientOptions } from './client';
+export { buildWebhookPayload } from './payload';
+export type { LarkWebhookPayload } from './payload';
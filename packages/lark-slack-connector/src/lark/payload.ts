@@ -0,0 +1,51 @@
+/**
+ * Lark custom-bot webhook payloads.
+ * The desktop app mirrors this format in `bridge_webhook_payload` (src-tauri/src/main.rs)
+ * to test a webhook with exactly what the bridge sends; keep the two in sync.
+ */
+
+export type LarkWebhookPayload =
+  | {
+      msg_type: 'interactive';
+      card: {
+        header: { title: { tag: 'plain_text'; content: string }; template?: string };
+        elements: Array<{ tag: 'markdown'; content: string }>;
+      };
+    }
+  | {
+      msg_type: 'text';
+      content: { text: string };
+    };
+
+/**
+ * A card with a plain-text header when there is a title, otherwise a text message.
+ * `color` is a Lark header template name (e.g. 'blue') and only applies to cards.
+ */
+export function buildWebhookPayload(text: string, title?: string, color?: string): LarkWebhookPayload {
+  if (title) {
+    return {
+      msg_type: 'interactive',
+      card: {
+        header: {
+          title: {
+            tag: 'plain_text',
+            content: title,
+          },
+          ...(color && { template: color }),
+        },
+        elements: [
+          {
+            tag: 'markdown',
+            content: text,
+          },
+        ],
+      },
+    };
+  }
+  return {
+    msg_type: 'text',
+    content: {
+      text,
+    },
+  };
+}
@@ -4,18 +4,23 @@
  */
 
 import { createServer, IncomingMessage, ServerResponse } from 'http';
-import { LarkSlackBridge } from '../bridge';
-import { BridgeConfig, BridgeStatus } from '../types';
+import { LarkSlackBridge, PauseBehavior } from '../bridge';
+import { BridgeConfig, BridgeStatus, SlackMessage } from '../types';
 
 export interface ServerOptions {
   port?: number;
   host?: string;
+  // Serves /debug/* endpoints; never enable in production
+  debug?: boolean;
+  // Served as-is by GET /config; must already be redacted
+  reportedConfig?: Record<string, unknown>;
 }
 
 export interface BridgeServerEvents {
   onStatusChange?: (status: BridgeStatus) => void;
   onLog?: (level: string, message: string) => void;
   onError?: (error: Error) => void;
+  onThreadStarted?: (larkConversationId: string, slackThreadTs: string) => void;
 }
 
 /**
@@ -26,17 +31,26 @@ export class BridgeServer {
   private server: ReturnType<typeof createServer> | null = null;
   private port: number;
   private host: string;
+  private debug: boolean;
+  private reportedConfig?: Record<string, unknown>;
   private events: BridgeServerEvents;
   private statusInterval: NodeJS.Timeout | null = null;
 
   constructor(config: BridgeConfig, options: ServerOptions = {}, events: BridgeServerEvents = {}) {
     this.port = options.port || 3456;
     this.host = options.host || '127.0.0.1';
+    this.debug = options.debug ?? false;
+    this.reportedConfig = options.reportedConfig;
     this.events = events;
 
     // Create bridge instance
     this.bridge = new LarkSlackBridge({ config });
 
+    this.bridge.on('bridge:thread', (event) => {
+      const data = event.data as { larkConversationId: string; slackThreadTs: string };
+      this.events.onThreadStarted?.(data.larkConversationId, data.slackThreadTs);
+    });
+
     // Set up event forwarding
     this.bridge.on('*', (event) => {
       if (this.events.onLog) {
@@ -147,6 +161,20 @@ export class BridgeServer {
         return;
       }
 
+      // The config this process was started with, secrets redacted
+      if (req.method === 'GET' && url === '/config' && this.reportedConfig) {
+        res.writeHead(200, { 'Content-Type': 'application/json' });
+        res.end(JSON.stringify(this.reportedConfig));
+        return;
+      }
+
+      // Channel filters, for diagnosing channels that don't forward
+      if (req.method === 'GET' && url === '/channels') {
+        res.writeHead(200, { 'Content-Type': 'application/json' });
+        res.end(JSON.stringify(this.bridge.getMonitoredChannels()));
+        return;
+      }
+
       // Lark webhook endpoint
       if (req.method === 'POST' && url === '/lark/webhook') {
         const body = await this.readBody(req);
@@ -162,6 +190,60 @@ export class BridgeServer {
         return;
       }
 
+      // Synthetic Slack message, forwarded like a real one (debug mode only)
+      if (this.debug && req.method === 'POST' && url === '/debug/inject') {
+        let body: Partial<SlackMessage> | null;
+        try {
+          body = JSON.parse(await this.readBody(req)) as Partial<SlackMessage> | null;
+        } catch {
+          res.writeHead(400, { 'Content-Type': 'application/json' });
+          res.end(JSON.stringify({ error: 'body must be JSON' }));
+          return;
+        }
+        if (!body?.channel || typeof body.text !== 'string') {
+          res.writeHead(400, { 'Content-Type': 'application/json' });
+          res.end(JSON.stringify({ error: 'channel and text are required' }));
+          return;
+        }
+        const message: SlackMessage = {
+          channel: body.channel,
+          user: body.user || 'U_DESKTOP_TEST',
+          text: body.text,
+          ts: body.ts || (Date.now() / 1000).toFixed(6),
+        };
+        this.events.onLog?.('debug', `テストメッセージ注入: ${message.channel}`);
+        // Answered only after forwarding finishes, so callers can time delivery
+        const forwarded = await this.bridge.injectSlackMessage(message);
+        res.writeHead(200, { 'Content-Type': 'application/json' });
+        res.end(JSON.stringify({ status: 'injected', ts: message.ts, forwarded }));
+        return;
+      }
+
+      // Pause/resume forwarding without dropping the Slack connection
+      if (req.method === 'POST' && url === '/pause') {
+        const body = await this.readBody(req);
+        const { behavior } = (body ? JSON.parse(body) : {}) as { behavior?: PauseBehavior };
+        this.bridge.pause(behavior === 'queue' || behavior === 'drop' ? behavior : undefined);
+        res.writeHead(200, { 'Content-Type': 'application/json' });
+        res.end(JSON.stringify({ paused: true }));
+        return;
+      }
+
+      if (req.method === 'POST' && url === '/resume') {
+        const replayed = await this.bridge.resume();
+        res.writeHead(200, { 'Content-Type': 'application/json' });
+        res.end(JSON.stringify({ paused: false, replayed }));
+        return;
+      }
+
+      // Drop Slack→Lark sends still waiting for a retry
+      if (req.method === 'POST' && url === '/flush') {
+        const dropped = this.bridge.flush();
+        res.writeHead(200, { 'Content-Type': 'application/json' });
+        res.end(JSON.stringify({ dropped }));
+        return;
+      }
+
       // Stop endpoint (for graceful shutdown from desktop app)
       if (req.method === 'POST' && url === '/stop') {
         res.writeHead(200, { 'Content-Type': 'application/json' });
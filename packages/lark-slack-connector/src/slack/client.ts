@@ -1,13 +1,21 @@
 import { App, LogLevel } from '@slack/bolt';
 import { SlackWorkspace, SlackMessage, SenderConfig } from '../types';
 
+export interface ReconnectOptions {
+  maxRetries: number;
+  baseDelayMs: number;
+}
+
 export interface SlackClientOptions {
   workspace: SlackWorkspace;
   socketMode?: boolean;
   logLevel?: 'debug' | 'info' | 'warn' | 'error';
   senderConfig?: SenderConfig;
+  reconnect?: ReconnectOptions;
 }
 
+const DEFAULT_RECONNECT: ReconnectOptions = { maxRetries: 10, baseDelayMs: 1000 };
+
 export class SlackClient {
   private app: App;
   // eslint-disable-next-line @typescript-eslint/no-explicit-any
@@ -16,11 +24,13 @@ export class SlackClient {
   private userWebClient: any;
   private workspace: SlackWorkspace;
   private senderConfig?: SenderConfig;
+  private reconnect: ReconnectOptions;
   private messageHandlers: Array<(message: SlackMessage) => void | Promise<void>> = [];
 
   constructor(options: SlackClientOptions) {
     this.workspace = options.workspace;
     this.senderConfig = options.senderConfig;
+    this.reconnect = options.reconnect ?? DEFAULT_RECONNECT;
 
     const logLevelMap: Record<string, LogLevel> = {
       debug: LogLevel.DEBUG,
@@ -39,12 +49,15 @@ export class SlackClient {
 
     // eslint-disable-next-line @typescript-eslint/no-require-imports
     const { WebClient } = require('@slack/web-api');
-    this.webClient = new WebClient(options.workspace.botToken);
+    const clientOptions = {
+      retryConfig: { retries: this.reconnect.maxRetries, factor: 2, minTimeout: this.reconnect.baseDelayMs },
+    };
+    this.webClient = new WebClient(options.workspace.botToken, clientOptions);
 
     // Create user web client if user token is available
     const userToken = options.senderConfig?.slackUserToken || options.workspace.userToken;
     if (userToken) {
-      this.userWebClient = new WebClient(userToken);
+      this.userWebClient = new WebClient(userToken, clientOptions);
     }
 
     this.setupEventHandlers();
@@ -117,30 +130,36 @@ export class SlackClient {
    * @param text - Message text
    * @param threadTs - Optional thread timestamp for replies
    * @param asUser - If true, send as user (requires user token)
+   * @returns The posted message's ts
    */
-  async sendMessage(channel: string, text: string, threadTs?: string, asUser?: boolean): Promise<void> {
+  async sendMessage(channel: string, text: string, threadTs?: string, asUser?: boolean): Promise<string | undefined> {
     const shouldSendAsUser = asUser ?? this.senderConfig?.sendAsUser ?? false;
     const client = shouldSendAsUser && this.userWebClient ? this.userWebClient : this.webClient;
+    const isBot = client === this.webClient;
 
-    await client.chat.postMessage({
+    const result = await client.chat.postMessage({
       channel,
       text,
       thread_ts: threadTs,
+      ...(isBot && this.senderConfig?.botUsername && { username: this.senderConfig.botUsername }),
+      ...(isBot && this.senderConfig?.botIconUrl && { icon_url: this.senderConfig.botIconUrl }),
     });
+    return result.ts;
   }
 
   /**
    * Send a message as the configured user (松井大樹)
    */
-  async sendMessageAsUser(channel: string, text: string, threadTs?: string): Promise<void> {
+  async sendMessageAsUser(channel: string, text: string, threadTs?: string): Promise<string | undefined> {
     if (!this.userWebClient) {
       throw new Error('User token is not configured. Cannot send as user.');
     }
-    await this.userWebClient.chat.postMessage({
+    const result = await this.userWebClient.chat.postMessage({
       channel,
       text,
       thread_ts: threadTs,
     });
+    return result.ts;
   }
 
   /**
@@ -195,10 +214,21 @@ export class SlackClient {
   }
 
   /**
-   * Start the Slack client
+   * Start the Slack client, retrying the connection with exponential backoff
    */
   async start(): Promise<void> {
-    await this.app.start();
+    for (let attempt = 0; ; attempt++) {
+      try {
+        await this.app.start();
+        return;
+      } catch (error) {
+        if (attempt >= this.reconnect.maxRetries) {
+          throw error;
+        }
+        const delay = this.reconnect.baseDelayMs * 2 ** attempt;
+        await new Promise((resolve) => setTimeout(resolve, delay));
+      }
+    }
   }
 
   /**
@@ -18,6 +18,9 @@ export const SenderConfigSchema = z.object({
   slackUserToken: z.string().optional(),
   // Lark user access token (if available)
   larkUserAccessToken: z.string().optional(),
+  // Bot post appearance when not sending as user (needs chat:write.customize)
+  botUsername: z.string().optional(),
+  botIconUrl: z.string().url().optional(),
 });
 
 export type SlackWorkspace = z.infer<typeof SlackWorkspaceSchema>;
@@ -111,6 +114,18 @@ export const BridgeConfigSchema = z.object({
 
     // Thread handling
     includeThreadReplies: z.boolean().default(true),
+    // Mark thread replies with threadReplyPrefix instead of flattening them
+    preserveThreads: z.boolean().optional(),
+    threadReplyPrefix: z.string().optional(),
+    // Replaces the default layout; {channel}, {author}, {text}, {timestamp}
+    larkMessageTemplate: z.string().optional(),
+    // Appended on its own line; {channel} is replaced
+    larkMessageFooter: z.string().optional(),
+    // Slack user id → Lark open_id, for turning <@U123> into Lark @mentions
+    userMappings: z.record(z.string()).optional(),
+    // Longer Slack→Lark messages are cut to this many characters, marker included
+    maxMessageLength: z.number().int().positive().optional(),
+    truncationMarker: z.string().optional(),
 
     // Polling for Slack Connect
     slackConnectPolling: z.boolean().default(false),
@@ -123,8 +138,26 @@ export const BridgeConfigSchema = z.object({
     maxRetries: z.number().default(3),
     retryDelayMs: z.number().default(1000),
 
+    // Slack connection retries (exponential backoff from the base delay)
+    reconnectMaxRetries: z.number().int().min(0).optional(),
+    reconnectBaseDelayMs: z.number().int().positive().optional(),
+
     // Logging
     logLevel: z.enum(['debug', 'info', 'warn', 'error']).default('info'),
+
+    // 'oneWaySlackToLark' ignores messages coming from Lark
+    mode: z.enum(['oneWaySlackToLark', 'bidirectional']).optional(),
+
+    // What pause() does with Slack messages until resume(): drop them or hold them
+    pauseBehavior: z.enum(['drop', 'queue']).optional(),
+
+    // Lark → Slack: thread messages from one Lark chat under its first Slack post
+    larkToSlackThreading: z.enum(['flat', 'threadByConversation']).optional(),
+    // Lark chat id → Slack thread ts, carried over from earlier runs
+    larkThreadMap: z.record(z.string()).optional(),
+
+    // Slack channel id → Lark card header template color
+    channelCardColors: z.record(z.string()).optional(),
   }).optional(),
 });
 
@@ -160,6 +193,7 @@ export type BridgeEventType =
   | 'slack:message'
   | 'lark:message'
   | 'bridge:forward'
+  | 'bridge:thread'
   | 'bridge:error'
   | 'bridge:connected'
   | 'bridge:disconnected';
@@ -185,6 +219,7 @@ export interface BridgeStatus {
     larkToSlack: number;
     errors: number;
   };
+  paused?: boolean;
   startedAt?: Date;
   uptime?: number;
 }
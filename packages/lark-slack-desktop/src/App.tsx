@@ -1,6 +1,7 @@
 import { useState, useRef, useEffect, useCallback } from 'react';
 
 interface BridgeStatus {
+  state?: 'stopped' | 'starting' | 'running' | 'stopping' | 'crashed';
   isRunning: boolean;
   slackConnected: boolean;
   larkConnected: boolean;
@@ -9,6 +10,17 @@ interface BridgeStatus {
     larkToSlack: number;
   };
   serverPort?: number;
+  lastRunAt?: string;
+  stale?: boolean;
+  slackLastError?: string | null;
+  larkLastError?: string | null;
+}
+
+/** start_bridge's rejection value */
+interface StartFailure {
+  message: string;
+  step?: string;
+  remediationUrl?: string;
 }
 
 interface LogEntry {
@@ -17,6 +29,15 @@ interface LogEntry {
   type: 'info' | 'success' | 'error';
 }
 
+/** Subset of get_full_state's result that the UI uses */
+interface FullState {
+  status: BridgeStatus;
+  logs: {
+    entries: Array<{ level: string; message: string; timestamp: string }>;
+    nextIndex: number;
+  };
+}
+
 interface SlackChannel {
   id: string;
   name: string;
@@ -122,6 +143,7 @@ function App() {
     },
   });
   const [isLoading, setIsLoading] = useState(false);
+  const [startFailure, setStartFailure] = useState<StartFailure | null>(null);
   const [isTesting, setIsTesting] = useState(false);
   const [nodeStatus, setNodeStatus] = useState<'checking' | 'installed' | 'missing'>('checking');
   const [availableChannels, setAvailableChannels] = useState<SlackChannel[]>([]);
@@ -147,6 +169,18 @@ function App() {
           addLog('Node.jsがインストールされていません', 'error');
         }
 
+        // Rebuild event-driven state in one call (the window may be a reload).
+        // Shows the last-known status until the bridge reports a live one.
+        const fullState = await invoke<FullState>('get_full_state');
+        setStatus(prev => ({ ...prev, ...fullState.status }));
+        if (fullState.logs.entries.length > 0) {
+          setLogs(fullState.logs.entries.slice(-100).map((entry) => ({
+            time: new Date(entry.timestamp).toLocaleTimeString('ja-JP'),
+            message: entry.message,
+            type: entry.level === 'error' ? 'error' : 'info',
+          })));
+        }
+
         // Listen for status updates
         const unlistenStatus = await listen('bridge-status', (event) => {
           const data = event.payload as BridgeStatus;
@@ -201,12 +235,19 @@ function App() {
     }
 
     setIsLoading(true);
+    setStartFailure(null);
     try {
       const newStatus = await invoke<BridgeStatus>('start_bridge');
       setStatus(newStatus);
       addLog('ブリッジを起動しました', 'success');
     } catch (error) {
-      addLog(`起動エラー: ${error}`, 'error');
+      // start_bridge rejects with { message, fields, step, remediationUrl } so
+      // every invalid field is reported at once and the failing step can be shown
+      const failure: StartFailure = typeof error === 'object' && error !== null && 'message' in error
+        ? (error as StartFailure)
+        : { message: String(error) };
+      addLog(`起動エラー: ${failure.message}`, 'error');
+      setStartFailure(failure);
     } finally {
       setIsLoading(false);
     }
@@ -319,6 +360,7 @@ function App() {
   };
 
   const getConnectionStatus = () => {
+    if (status.state === 'starting') return 'connecting';
     if (!status.isRunning) return 'disconnected';
     if (status.slackConnected && status.larkConnected) return 'connected';
     return 'connecting';
@@ -359,11 +401,38 @@ function App() {
           </div>
         )}
 
+        {startFailure && (
+          <div className="card" style={{ background: 'rgba(239, 68, 68, 0.1)', borderColor: '#ef4444' }}>
+            <div style={{ display: 'flex', alignItems: 'center', gap: 8, color: '#ef4444' }}>
+              <span>⚠️</span>
+              <div style={{ flex: 1 }}>
+                <strong>ブリッジを起動できませんでした</strong>
+                <p style={{ margin: '4px 0 0', fontSize: 12, opacity: 0.8 }}>{startFailure.message}</p>
+              </div>
+              {startFailure.remediationUrl && (
+                <button
+                  className="btn btn-secondary"
+                  onClick={async () => {
+                    const { open } = await import('@tauri-apps/api/shell');
+                    await open(startFailure.remediationUrl!);
+                  }}
+                >
+                  対処方法を見る
+                </button>
+              )}
+            </div>
+          </div>
+        )}
+
         <div className="card">
           <div className="card-header">
             <h2 className="card-title">📊 統計</h2>
             <span style={{ fontSize: 12, color: 'var(--text-secondary)' }}>
-              {status.serverPort ? `ローカルサーバー: port ${status.serverPort}` : '稼働時間: -'}
+              {status.serverPort
+                ? `ローカルサーバー: port ${status.serverPort}`
+                : status.stale && status.lastRunAt
+                  ? `前回の実行: ${new Date(status.lastRunAt).toLocaleString('ja-JP')}`
+                  : '稼働時間: -'}
             </span>
           </div>
           <div className="stats-grid">
@@ -385,7 +454,11 @@ function App() {
               <span className="connection-icon">💬</span>
               <div>
                 <div className="connection-name">Slack</div>
-                <div className="connection-detail">Socket Mode (リアルタイム受信)</div>
+                <div className="connection-detail">
+                  {!status.slackConnected && status.slackLastError
+                    ? `Slack: ${status.slackLastError}`
+                    : 'Socket Mode (リアルタイム受信)'}
+                </div>
               </div>
             </div>
             <span className="connection-status">
@@ -397,7 +470,11 @@ function App() {
               <span className="connection-icon">🐦</span>
               <div>
                 <div className="connection-name">Lark</div>
-                <div className="connection-detail">Webhook (送信 + 受信サーバー)</div>
+                <div className="connection-detail">
+                  {!status.larkConnected && status.larkLastError
+                    ? `Lark: ${status.larkLastError}`
+                    : 'Webhook (送信 + 受信サーバー)'}
+                </div>
               </div>
             </div>
             <span className="connection-status">
@@ -424,7 +501,7 @@ function App() {
           ⚙️ 設定
         </button>
         <div style={{ display: 'flex', gap: 8 }}>
-          {status.isRunning ? (
+          {status.isRunning || status.state === 'starting' ? (
             <button className="btn btn-danger" onClick={handleStop} disabled={isLoading}>
               {isLoading ? '処理中...' : '⏹ 停止'}
             </button>